@@ -89,6 +89,12 @@ impl ForeSystem {
         result
     }
 
+    /// Number of GFp2 elements `process_data` produces for `input_len` bytes
+    pub fn output_len(&self, input_len: usize) -> usize {
+        // One field element per input byte, no padding
+        input_len
+    }
+
     /// Verify wavelet relationships are maintained
     pub fn verify_relationships(&self, data: &[GFp2]) -> bool {
         let mut level = 0;
@@ -114,6 +120,20 @@ impl ForeSystem {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_len_matches_process_data() {
+        let system = ForeSystem::new(0xDEADBEEF);
+
+        for n in [0, 1, 2, 7, 64, 1000] {
+            assert_eq!(system.output_len(n), system.process_data(&vec![0u8; n]).len());
+        }
+    }
+}
+
 #[cfg(test)]
 mod performance_tests {
     use super::*;